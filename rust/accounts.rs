@@ -28,5 +28,8 @@ pub async fn accounts() {
         .get_order_record(&order_records.data[0].orders[0].order_id)
         .await
         .unwrap();
-    println!("\nOrder Record: {:?}", order_record);
+    println!(
+        "\nOrder Record: {:?} (status: {:?}, filled: {}, remaining: {})\n",
+        order_record, order_record.status, order_record.filled_quantity, order_record.remaining_quantity
+    );
 }
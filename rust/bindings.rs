@@ -0,0 +1,44 @@
+use deltadefi::bindings_core::{DeltaDeFiHandle, dispatch};
+use deltadefi::Stage;
+use dotenv::dotenv;
+use serde_json::json;
+use std::env;
+
+/// Demonstrates the opaque-handle + JSON dispatch shape that the Node.js,
+/// Python, and WASM wrappers marshal strings through; the core owns client
+/// construction and request signing so none of it needs reimplementing there.
+pub async fn bindings() {
+    dotenv().ok();
+    let api_key = env::var("DELTADEFI_API_KEY").expect("DELTADEFI_API_KEY must be set");
+    let encryption_passcode =
+        env::var("ENCRYPTION_PASSCODE").expect("ENCRYPTION_PASSCODE must be set");
+
+    let handle = DeltaDeFiHandle::new(api_key, Stage::Staging, None).unwrap();
+    handle.load_operation_key(&encryption_passcode).await.unwrap();
+
+    // Get aggregated price
+    let res = dispatch(
+        &handle,
+        "market.get_aggregated_price",
+        json!({ "symbol": "ADAUSDM", "interval": "1d", "start": 1_732_982_400, "end": 1_732_982_400 }),
+    )
+    .await
+    .unwrap();
+    println!("\nDispatch market.get_aggregated_price:\n{}", res);
+
+    // Post order
+    let res = dispatch(
+        &handle,
+        "post_order",
+        json!({
+            "symbol": "ADAUSDM",
+            "side": "sell",
+            "type": "limit",
+            "quantity": 100.0,
+            "price": 51.0,
+        }),
+    )
+    .await
+    .unwrap();
+    println!("\nDispatch post_order:\n{}", res);
+}
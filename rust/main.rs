@@ -1,10 +1,20 @@
 mod accounts;
+mod bindings;
 mod market;
+mod market_maker;
 mod order;
+mod server;
+mod stream;
 
 #[tokio::main]
 async fn main() {
     // accounts::accounts().await;
     market::market().await;
     // order::order().await;
+    // order::batch_order().await;
+    // order::amend_order().await;
+    // server::server().await;
+    // bindings::bindings().await;
+    // market_maker::market_maker().await;
+    // stream::stream().await;
 }
@@ -0,0 +1,49 @@
+use deltadefi::{DeltaDeFi, MarketMakerConfig, Stage, Symbol};
+use dotenv::dotenv;
+use std::env;
+use std::time::Duration;
+
+/// Runs a two-sided quoting loop on top of the order/market APIs: each tick it
+/// re-centers a bid/ask around the mid price, cancels the stale quotes and
+/// posts fresh ones via the batch API, and skews the quotes by the caller's
+/// current net position.
+pub async fn market_maker() {
+    dotenv().ok();
+    let api_key = env::var("DELTADEFI_API_KEY").expect("DELTADEFI_API_KEY must be set");
+    let encryption_passcode =
+        env::var("ENCRYPTION_PASSCODE").expect("ENCRYPTION_PASSCODE must be set");
+
+    let mut deltadefi = DeltaDeFi::new(api_key, Stage::Staging, None).unwrap();
+    deltadefi
+        .load_operation_key(&encryption_passcode)
+        .await
+        .unwrap();
+
+    let config = MarketMakerConfig {
+        symbol: Symbol::ADAUSDM,
+        spread_bps: 20,
+        order_size: 100.0,
+        refresh_interval: Duration::from_secs(5),
+        inventory_skew_bps_per_unit: 1,
+    };
+
+    let handle = deltadefi
+        .market_maker(config)
+        .start()
+        .await
+        .expect("Failed to start market maker");
+
+    // Let it run a few ticks, printing fills as they're diffed against the
+    // previous open-order snapshot.
+    let mut fills = handle.fills();
+    tokio::time::timeout(Duration::from_secs(30), async {
+        while let Some(fill) = fills.recv().await {
+            println!("\nFill:\n{:?}", fill);
+        }
+    })
+    .await
+    .ok();
+
+    handle.stop().await;
+    println!("\nMarket maker stopped\n");
+}
@@ -1,4 +1,4 @@
-use deltadefi::{DeltaDeFi, OrderSide, OrderType, Stage};
+use deltadefi::{DeltaDeFi, OrderRequest, OrderSide, OrderType, Stage, Symbol};
 use dotenv::dotenv;
 use std::env;
 
@@ -25,6 +25,7 @@ pub async fn order() {
             Some(51.0),
             Some(false),
             None,
+            Some("order-1".to_string()),
         )
         .await
         .expect("Failed to post order");
@@ -38,3 +39,110 @@ pub async fn order() {
 
     println!("\nCancel order successful\n");
 }
+
+pub async fn batch_order() {
+    dotenv().ok();
+    let api_key = env::var("DELTADEFI_API_KEY").expect("DELTADEFI_API_KEY must be set");
+    let encryption_passcode =
+        env::var("ENCRYPTION_PASSCODE").expect("ENCRYPTION_PASSCODE must be set");
+
+    let mut deltadefi = DeltaDeFi::new(api_key, Stage::Staging, None).unwrap();
+    deltadefi
+        .load_operation_key(&encryption_passcode)
+        .await
+        .unwrap();
+
+    // Submit a batch of quotes in one call; a rejection on one order does not
+    // abort the rest of the batch.
+    let requests = vec![
+        OrderRequest {
+            symbol: "ADAUSDM".to_string(),
+            side: OrderSide::Sell,
+            r#type: OrderType::Limit,
+            quantity: 100.0,
+            price: Some(51.0),
+            post_only: Some(false),
+            client_order_id: Some("quote-sell-1".to_string()),
+        },
+        OrderRequest {
+            symbol: "ADAUSDM".to_string(),
+            side: OrderSide::Buy,
+            r#type: OrderType::Limit,
+            quantity: 100.0,
+            price: Some(49.0),
+            post_only: Some(false),
+            client_order_id: Some("quote-buy-1".to_string()),
+        },
+    ];
+
+    let results = deltadefi
+        .post_orders(requests)
+        .await
+        .expect("Failed to post order batch");
+    println!("\nPost orders batch:\n{:?}", results);
+
+    let order_ids: Vec<String> = results
+        .iter()
+        .filter_map(|r| r.order.as_ref().map(|order| order.order_id.clone()))
+        .collect();
+
+    deltadefi
+        .cancel_orders(&order_ids)
+        .await
+        .expect("Failed to cancel order batch");
+    println!("\nCancel orders batch successful\n");
+
+    deltadefi
+        .cancel_order_by_client_order_id("quote-buy-1")
+        .await
+        .ok();
+
+    deltadefi
+        .cancel_all_orders(Some(Symbol::ADAUSDM))
+        .await
+        .expect("Failed to cancel all orders");
+    println!("\nCancel all orders for ADAUSDM successful\n");
+}
+
+pub async fn amend_order() {
+    dotenv().ok();
+    let api_key = env::var("DELTADEFI_API_KEY").expect("DELTADEFI_API_KEY must be set");
+    let encryption_passcode =
+        env::var("ENCRYPTION_PASSCODE").expect("ENCRYPTION_PASSCODE must be set");
+
+    let mut deltadefi = DeltaDeFi::new(api_key, Stage::Staging, None).unwrap();
+    deltadefi
+        .load_operation_key(&encryption_passcode)
+        .await
+        .unwrap();
+
+    let res = deltadefi
+        .post_order(
+            "ADAUSDM",
+            OrderSide::Sell,
+            OrderType::Limit,
+            100.0,
+            Some(51.0),
+            Some(false),
+            None,
+            Some("order-2".to_string()),
+        )
+        .await
+        .expect("Failed to post order");
+
+    println!("\nPost order:\n{:?}", res);
+
+    // Reprice without the caller having to cancel and re-post; the SDK falls
+    // back to an atomic cancel-replace where the venue has no native amend.
+    let res = deltadefi
+        .amend_order(&res.order.order_id, Some(50.5), None)
+        .await
+        .expect("Failed to amend order");
+    println!("\nAmend order:\n{:?}", res);
+
+    let order_record = deltadefi
+        .wait_for_fill(&res.order.order_id, std::time::Duration::from_secs(30))
+        .await
+        .expect("Failed waiting for fill");
+    println!("\nOrder record after waiting for fill:\n{:?}", order_record);
+}
@@ -0,0 +1,33 @@
+use deltadefi::rpc::DeltaDeFiRpcServer;
+use deltadefi::{DeltaDeFi, Stage};
+use dotenv::dotenv;
+use std::env;
+use std::net::SocketAddr;
+
+pub async fn server() {
+    dotenv().ok();
+    let api_key = env::var("DELTADEFI_API_KEY").expect("DELTADEFI_API_KEY must be set");
+    let encryption_passcode =
+        env::var("ENCRYPTION_PASSCODE").expect("ENCRYPTION_PASSCODE must be set");
+
+    // Loaded once, server-side: the passcode never crosses the RPC boundary.
+    let mut deltadefi = DeltaDeFi::new(api_key, Stage::Staging, None).unwrap();
+    deltadefi
+        .load_operation_key(&encryption_passcode)
+        .await
+        .unwrap();
+
+    // Defaults to localhost only; override with DELTADEFI_RPC_ADDR to expose it further.
+    let addr: SocketAddr = env::var("DELTADEFI_RPC_ADDR")
+        .unwrap_or_else(|_| "127.0.0.1:8645".to_string())
+        .parse()
+        .expect("DELTADEFI_RPC_ADDR must be a valid socket address");
+
+    let handle = DeltaDeFiRpcServer::new(deltadefi)
+        .start(addr)
+        .await
+        .expect("failed to start RPC server");
+
+    println!("\nJSON-RPC server listening on {}\n", addr);
+    handle.stopped().await;
+}
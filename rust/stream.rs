@@ -0,0 +1,59 @@
+use deltadefi::stream::{BookDelta, OrderUpdate, PriceTick};
+use deltadefi::{DeltaDeFi, Stage, Symbol};
+use dotenv::dotenv;
+use futures::StreamExt;
+use std::env;
+
+/// Opens a WebSocket to the venue and consumes the public price/book-depth
+/// channels plus the authenticated private order/balance channel. The
+/// underlying connection reconnects and resubscribes automatically on drop,
+/// so this only needs to iterate the typed event streams.
+pub async fn stream() {
+    dotenv().ok();
+    let api_key = env::var("DELTADEFI_API_KEY").expect("DELTADEFI_API_KEY must be set");
+    let encryption_passcode =
+        env::var("ENCRYPTION_PASSCODE").expect("ENCRYPTION_PASSCODE must be set");
+
+    let mut deltadefi = DeltaDeFi::new(api_key, Stage::Staging, None).unwrap();
+    deltadefi
+        .load_operation_key(&encryption_passcode)
+        .await
+        .unwrap();
+
+    let mut prices = deltadefi
+        .stream
+        .price_ticks(Symbol::ADAUSDM)
+        .await
+        .expect("Failed to subscribe to price ticks");
+
+    let mut book = deltadefi
+        .stream
+        .book_deltas(Symbol::ADAUSDM)
+        .await
+        .expect("Failed to subscribe to book deltas");
+
+    // Reuses the already-loaded operation key for the private auth handshake.
+    let mut orders = deltadefi
+        .stream
+        .order_updates()
+        .await
+        .expect("Failed to subscribe to order updates");
+
+    loop {
+        tokio::select! {
+            Some(tick) = prices.next() => {
+                let tick: PriceTick = tick;
+                println!("\nPrice tick:\n{:?}", tick);
+            }
+            Some(delta) = book.next() => {
+                let delta: BookDelta = delta;
+                println!("\nBook delta:\n{:?}", delta);
+            }
+            Some(update) = orders.next() => {
+                let update: OrderUpdate = update;
+                println!("\nOrder update:\n{:?}", update);
+            }
+            else => break,
+        }
+    }
+}